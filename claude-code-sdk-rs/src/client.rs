@@ -5,14 +5,16 @@
 
 use crate::{
     errors::{Result, SdkError},
-    transport::{InputMessage, SubprocessTransport, Transport},
+    transport::{InputMessage, SubprocessTransport, Transport, TransportEvent},
     types::{ClaudeCodeOptions, ControlRequest, ControlResponse, Message},
 };
-use futures::stream::{Stream, StreamExt};
+#[cfg(test)]
+use crate::transport::MockTransport;
+use futures::stream::{self, Stream, StreamExt};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex, RwLock};
-use tokio_stream::wrappers::ReceiverStream;
+use tokio::sync::{broadcast, oneshot, watch, Mutex, RwLock};
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
 use tracing::{debug, error, info};
 
 /// Client state
@@ -22,10 +24,41 @@ pub enum ClientState {
     Disconnected,
     /// Connected and ready
     Connected,
+    /// Transport was lost and a reconnect attempt is in flight
+    Reconnecting,
     /// Error state
     Error,
 }
 
+/// Policy controlling automatic reconnection after the transport errors
+///
+/// Opt in by setting `ClaudeCodeOptions::reconnect`; when left unset the
+/// client keeps its previous behavior of going straight to `ClientState::Error`.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Maximum number of reconnect attempts before giving up and entering `ClientState::Error`
+    pub max_attempts: u32,
+    /// Delay before the first attempt; doubles after each failed attempt
+    pub initial_backoff: std::time::Duration,
+    /// Re-announce active sessions to the CLI after a successful reconnect
+    ///
+    /// Off by default: `InputMessage::session_resume`'s wire format is a
+    /// best-effort guess at the CLI's resume protocol, not a confirmed one,
+    /// so sending it may be a silent no-op. Turn this on only once that
+    /// format has been verified against the CLI version in use.
+    pub resume_sessions: bool,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: std::time::Duration::from_millis(500),
+            resume_sessions: false,
+        }
+    }
+}
+
 /// Interactive client for bidirectional communication with Claude
 ///
 /// `ClaudeSDKClient` provides a stateful, interactive interface for communicating
@@ -79,24 +112,41 @@ pub enum ClientState {
 ///     Ok(())
 /// }
 /// ```
-pub struct ClaudeSDKClient {
+pub struct ClaudeSDKClient<T: Transport = SubprocessTransport> {
     /// Configuration options
     #[allow(dead_code)]
     options: ClaudeCodeOptions,
     /// Transport layer
-    transport: Arc<Mutex<SubprocessTransport>>,
+    transport: Arc<Mutex<T>>,
     /// Client state
     state: Arc<RwLock<ClientState>>,
     /// Active sessions
     sessions: Arc<RwLock<HashMap<String, SessionData>>>,
-    /// Message sender for current receiver
-    message_tx: Arc<Mutex<Option<mpsc::Sender<Result<Message>>>>>,
-    /// Message buffer for multiple receivers
-    message_buffer: Arc<Mutex<Vec<Message>>>,
+    /// Broadcast fan-out for each session, so multiple subscribers can read it at once
+    session_broadcasters: Arc<Mutex<HashMap<String, broadcast::Sender<Result<Message>>>>>,
+    /// Bounded ring buffer of each session's recent messages, replayed to new subscribers
+    session_buffers: Arc<Mutex<HashMap<String, Vec<Message>>>>,
     /// Request counter
     request_counter: Arc<Mutex<u64>>,
+    /// Control requests awaiting their response, keyed by request ID
+    pending_control: Arc<Mutex<HashMap<String, oneshot::Sender<ControlResponse>>>>,
+    /// Ticks once per successful reconnect, so callers can observe the boundary
+    reconnect_tx: watch::Sender<u64>,
 }
 
+/// Session ID used when a caller does not specify one
+const DEFAULT_SESSION_ID: &str = "default";
+
+/// Internal buffer capacity of each session's broadcast channel
+///
+/// Exceeding this many unconsumed messages across all subscribers of a
+/// session causes the slowest subscriber to miss some and see a `Lagged`
+/// error from its stream, per `tokio::sync::broadcast`'s overflow behavior.
+const BROADCAST_CAPACITY: usize = 256;
+
+/// Number of recent messages per session replayed to a newly attached subscriber
+const REPLAY_BUFFER_CAPACITY: usize = 50;
+
 /// Session data
 #[allow(dead_code)]
 struct SessionData {
@@ -108,7 +158,7 @@ struct SessionData {
     created_at: std::time::Instant,
 }
 
-impl ClaudeSDKClient {
+impl ClaudeSDKClient<SubprocessTransport> {
     /// Create a new client with the given options
     pub fn new(options: ClaudeCodeOptions) -> Self {
         // Set environment variable to indicate SDK usage
@@ -123,17 +173,42 @@ impl ClaudeSDKClient {
             }
         };
 
+        Self::from_transport(options, transport)
+    }
+}
+
+impl<T: Transport + 'static> ClaudeSDKClient<T> {
+    /// Build a client around an already-constructed transport
+    ///
+    /// This is the hook tests use to swap in a [`crate::transport::MockTransport`]
+    /// instead of spawning the real Claude CLI; production code should go through
+    /// [`ClaudeSDKClient::new`] instead.
+    pub fn from_transport(options: ClaudeCodeOptions, transport: T) -> Self {
+        let (reconnect_tx, _) = watch::channel(0u64);
+
         Self {
             options,
             transport: Arc::new(Mutex::new(transport)),
             state: Arc::new(RwLock::new(ClientState::Disconnected)),
             sessions: Arc::new(RwLock::new(HashMap::new())),
-            message_tx: Arc::new(Mutex::new(None)),
-            message_buffer: Arc::new(Mutex::new(Vec::new())),
+            session_broadcasters: Arc::new(Mutex::new(HashMap::new())),
+            session_buffers: Arc::new(Mutex::new(HashMap::new())),
             request_counter: Arc::new(Mutex::new(0)),
+            pending_control: Arc::new(Mutex::new(HashMap::new())),
+            reconnect_tx,
         }
     }
 
+    /// Subscribe to reconnect boundaries
+    ///
+    /// The watched value increments by one each time the client successfully
+    /// reconnects after a transport error, so consumers of `receive_messages`
+    /// can learn a boundary occurred (and e.g. expect a fresh turn) without
+    /// it being folded into the message stream itself.
+    pub fn subscribe_reconnect(&self) -> watch::Receiver<u64> {
+        self.reconnect_tx.subscribe()
+    }
+
     /// Connect to Claude CLI with an optional initial prompt
     pub async fn connect(&mut self, initial_prompt: Option<String>) -> Result<()> {
         // Check if already connected
@@ -169,10 +244,24 @@ impl ClaudeSDKClient {
         Ok(())
     }
 
-    /// Send a user message to Claude
+    /// Send a user message to Claude on the default session
     pub async fn send_user_message(
         &mut self,
         prompt: String,
+    ) -> Result<()> {
+        self.send_request(prompt, None).await
+    }
+
+    /// Send a request to Claude, optionally on a specific session
+    ///
+    /// Passing `None` uses the default session, the same one `send_user_message`
+    /// and `receive_messages` target. Concurrent conversations can be kept apart
+    /// by passing distinct `session_id`s and reading each back with
+    /// `receive_messages_for`.
+    pub async fn send_request(
+        &mut self,
+        prompt: String,
+        session_id: Option<String>,
     ) -> Result<()> {
         // Check connection
         {
@@ -184,8 +273,7 @@ impl ClaudeSDKClient {
             }
         }
 
-        // Use default session ID
-        let session_id = "default".to_string();
+        let session_id = session_id.unwrap_or_else(|| DEFAULT_SESSION_ID.to_string());
 
         // Update session data
         {
@@ -209,51 +297,68 @@ impl ClaudeSDKClient {
             transport.send_message(message).await?;
         }
 
-        debug!("Sent request to Claude");
+        debug!("Sent request to Claude for session {}", session_id);
         Ok(())
     }
 
-    /// Send a request to Claude (alias for send_user_message with optional session_id)
-    pub async fn send_request(
-        &mut self,
-        prompt: String,
-        _session_id: Option<String>,
-    ) -> Result<()> {
-        // For now, ignore session_id and use send_user_message
-        self.send_user_message(prompt).await
-    }
-
-    /// Receive messages from Claude
+    /// Receive messages from Claude on the default session
     ///
     /// Returns a stream of messages. The stream will end when a Result message
     /// is received or the connection is closed.
     pub async fn receive_messages(&mut self) -> impl Stream<Item = Result<Message>> + use<> {
-        // Create a new channel for this receiver
-        let (tx, rx) = mpsc::channel(100);
+        self.receive_messages_for(DEFAULT_SESSION_ID).await
+    }
 
-        // Get buffered messages and clear buffer
-        let buffered_messages = {
-            let mut buffer = self.message_buffer.lock().await;
-            std::mem::take(&mut *buffer)
+    /// Receive messages from Claude for a specific session
+    ///
+    /// Returns a new, independent subscriber each call: every caller gets its
+    /// own stream of messages whose `session_id` matches `session_id`, so two
+    /// (or more) callers can hold concurrent streams for the same session
+    /// without stealing it from one another. A subscriber first replays the
+    /// session's recent message history, then attaches to the live broadcast.
+    ///
+    /// If a subscriber falls too far behind the others to keep up, it sees a
+    /// `Lagged` error on the stream instead of silently missing turns - that
+    /// is `tokio::sync::broadcast`'s overflow behavior, not a bug; a slow
+    /// consumer should treat it as a signal to catch up or resubscribe.
+    pub async fn receive_messages_for(
+        &mut self,
+        session_id: impl Into<String>,
+    ) -> impl Stream<Item = Result<Message>> + use<> {
+        let session_id = session_id.into();
+
+        // Subscribe and snapshot the replay buffer under one critical
+        // section, holding `session_broadcasters` (the outer lock) across
+        // both, the same order `start_message_receiver` holds it in while
+        // pushing a message to the buffer and sending it to the broadcast.
+        // Without that, a message handled in the gap between subscribing
+        // and snapshotting would land in both the snapshot and the live
+        // broadcast - delivered twice.
+        let (replayed, receiver) = {
+            let mut broadcasters = self.session_broadcasters.lock().await;
+            let tx = broadcasters
+                .entry(session_id.clone())
+                .or_insert_with(|| broadcast::channel(BROADCAST_CAPACITY).0);
+            let receiver = tx.subscribe();
+
+            let buffers = self.session_buffers.lock().await;
+            let replayed = buffers.get(&session_id).cloned().unwrap_or_default();
+            (replayed, receiver)
         };
 
-        // Send buffered messages to the new receiver
-        let tx_clone = tx.clone();
-        tokio::spawn(async move {
-            for msg in buffered_messages {
-                if tx_clone.send(Ok(msg)).await.is_err() {
-                    break;
-                }
-            }
+        let live = BroadcastStream::new(receiver).map(|item| match item {
+            Ok(result) => result,
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => Err(SdkError::lagged(skipped)),
         });
 
-        // Store the sender for the message receiver task
-        {
-            let mut message_tx = self.message_tx.lock().await;
-            *message_tx = Some(tx);
-        }
+        stream::iter(replayed.into_iter().map(Ok)).chain(live)
+    }
 
-        ReceiverStream::new(rx)
+    /// Drop a session's state without tearing down the whole connection
+    pub async fn close_session(&mut self, session_id: &str) {
+        self.sessions.write().await.remove(session_id);
+        self.session_broadcasters.lock().await.remove(session_id);
+        self.session_buffers.lock().await.remove(session_id);
     }
 
     /// Send an interrupt request
@@ -275,6 +380,14 @@ impl ClaudeSDKClient {
             format!("interrupt_{}", *counter)
         };
 
+        // Register a oneshot to be woken by start_message_receiver once the
+        // matching control response comes off the single transport stream.
+        let (tx, rx) = oneshot::channel();
+        {
+            let mut pending = self.pending_control.lock().await;
+            pending.insert(request_id.clone(), tx);
+        }
+
         // Send interrupt request
         let request = ControlRequest::Interrupt {
             request_id: request_id.clone(),
@@ -288,38 +401,29 @@ impl ClaudeSDKClient {
         info!("Sent interrupt request: {}", request_id);
 
         // Wait for acknowledgment (with timeout)
-        let transport = self.transport.clone();
-        let ack_task = tokio::spawn(async move {
-            let mut transport = transport.lock().await;
-            match tokio::time::timeout(
-                std::time::Duration::from_secs(5),
-                transport.receive_control_response(),
-            )
-            .await
-            {
-                Ok(Ok(Some(ControlResponse::InterruptAck {
-                    request_id: ack_id,
-                    success,
-                }))) => {
-                    if ack_id == request_id && success {
-                        Ok(())
-                    } else {
-                        Err(SdkError::ControlRequestError(
-                            "Interrupt not acknowledged successfully".into(),
-                        ))
-                    }
+        let result = match tokio::time::timeout(std::time::Duration::from_secs(5), rx).await {
+            Ok(Ok(ControlResponse::InterruptAck {
+                request_id: ack_id,
+                success,
+            })) => {
+                if ack_id == request_id && success {
+                    Ok(())
+                } else {
+                    Err(SdkError::ControlRequestError(
+                        "Interrupt not acknowledged successfully".into(),
+                    ))
                 }
-                Ok(Ok(None)) => Err(SdkError::ControlRequestError(
-                    "No interrupt acknowledgment received".into(),
-                )),
-                Ok(Err(e)) => Err(e),
-                Err(_) => Err(SdkError::timeout(5)),
             }
-        });
+            Ok(Err(_)) => Err(SdkError::ControlRequestError(
+                "Control response channel closed before acknowledgment".into(),
+            )),
+            Err(_) => Err(SdkError::timeout(5)),
+        };
+
+        // A timed-out or dropped request must not leak its entry in the map.
+        self.pending_control.lock().await.remove(&request_id);
 
-        ack_task.await.map_err(|_| {
-            SdkError::ControlRequestError("Interrupt task panicked".into())
-        })?
+        result
     }
 
     /// Check if the client is connected
@@ -361,6 +465,8 @@ impl ClaudeSDKClient {
             let mut sessions = self.sessions.write().await;
             sessions.clear();
         }
+        self.session_broadcasters.lock().await.clear();
+        self.session_buffers.lock().await.clear();
 
         info!("Disconnected from Claude CLI");
         Ok(())
@@ -369,48 +475,175 @@ impl ClaudeSDKClient {
     /// Start the message receiver task
     async fn start_message_receiver(&mut self) {
         let transport = self.transport.clone();
-        let message_tx = self.message_tx.clone();
-        let message_buffer = self.message_buffer.clone();
+        let session_broadcasters = self.session_broadcasters.clone();
+        let session_buffers = self.session_buffers.clone();
         let state = self.state.clone();
+        let pending_control = self.pending_control.clone();
+        let sessions = self.sessions.clone();
+        let reconnect_policy = self.options.reconnect.clone();
+        let reconnect_tx = self.reconnect_tx.clone();
 
         tokio::spawn(async move {
-            let mut transport = transport.lock().await;
-            let mut stream = transport.receive_messages();
-
-            while let Some(result) = stream.next().await {
-                match result {
-                    Ok(message) => {
-                        // Try to send to current receiver
-                        let sent = {
-                            let mut tx_opt = message_tx.lock().await;
-                            if let Some(tx) = tx_opt.as_mut() {
-                                tx.send(Ok(message.clone())).await.is_ok()
-                            } else {
-                                false
+            // This task is the only reader of the transport: it demuxes
+            // control responses to their waiting `interrupt()` caller via
+            // `pending_control` and everything else to the session channels.
+            // A transport error tries the reconnect policy (if any) before
+            // falling back to `ClientState::Error`.
+            'session: loop {
+                // Lock only long enough to obtain the event stream, which
+                // per `Transport::receive_events`'s contract does not borrow
+                // the transport. Holding the guard for the whole read loop
+                // would starve `send_request`/`interrupt`, which need this
+                // same lock to write, for as long as the connection lives.
+                let mut events = {
+                    let mut transport = transport.lock().await;
+                    transport.receive_events()
+                };
+                let mut transport_error = None;
+
+                while let Some(event) = events.next().await {
+                    match event {
+                        TransportEvent::Control(response) => {
+                            let ControlResponse::InterruptAck { request_id, .. } = &response;
+                            let sender = pending_control.lock().await.remove(request_id);
+                            match sender {
+                                Some(sender) => {
+                                    let _ = sender.send(response);
+                                }
+                                None => {
+                                    debug!(
+                                        "Received control response for unknown or timed-out request: {}",
+                                        request_id
+                                    );
+                                }
+                            }
+                        }
+                        TransportEvent::Message(Ok(message)) => {
+                            let session_id = message
+                                .session_id()
+                                .unwrap_or(DEFAULT_SESSION_ID)
+                                .to_string();
+
+                            // Push to the replay buffer and fan out to every
+                            // current subscriber as one critical section,
+                            // holding `session_broadcasters` (the outer lock)
+                            // across both. `receive_messages_for` takes the
+                            // same lock in the same order around its
+                            // subscribe-and-snapshot, so a message is always
+                            // seen exactly once by a new subscriber - either
+                            // already in the buffer it snapshots, or live on
+                            // the broadcast it had already joined, never both.
+                            let broadcasters = session_broadcasters.lock().await;
+                            {
+                                let mut buffers = session_buffers.lock().await;
+                                let buffer = buffers.entry(session_id.clone()).or_default();
+                                buffer.push(message.clone());
+                                if buffer.len() > REPLAY_BUFFER_CAPACITY {
+                                    buffer.remove(0);
+                                }
                             }
-                        };
 
-                        // If no receiver or send failed, buffer the message
-                        if !sent {
-                            let mut buffer = message_buffer.lock().await;
-                            buffer.push(message);
+                            // A send with no receivers is not an error: the
+                            // message is already in the replay buffer for
+                            // whoever subscribes next.
+                            if let Some(tx) = broadcasters.get(&session_id) {
+                                let _ = tx.send(Ok(message));
+                            }
+                        }
+                        TransportEvent::Message(Err(e)) => {
+                            error!("Error receiving message: {}", e);
+                            transport_error = Some(e);
+                            break;
                         }
                     }
-                    Err(e) => {
-                        error!("Error receiving message: {}", e);
+                }
 
-                        // Send error to receiver if available
-                        let mut tx_opt = message_tx.lock().await;
-                        if let Some(tx) = tx_opt.as_mut() {
-                            let _ = tx.send(Err(e)).await;
+                drop(events);
+
+                let Some(e) = transport_error else {
+                    // The stream ended cleanly (e.g. `disconnect` was called).
+                    // Drop the broadcast senders so every subscriber's stream
+                    // ends too instead of waiting on a closed connection.
+                    session_broadcasters.lock().await.clear();
+                    break 'session;
+                };
+
+                // The error isn't tied to one session, so broadcasting it
+                // means every currently subscribed session needs to see it.
+                let broadcast_error = |e: SdkError| {
+                    let session_broadcasters = session_broadcasters.clone();
+                    async move {
+                        let broadcasters = session_broadcasters.lock().await;
+                        for tx in broadcasters.values() {
+                            let _ = tx.send(Err(e.clone()));
                         }
-
-                        // Update state on error
-                        let mut state = state.write().await;
-                        *state = ClientState::Error;
+                    }
+                };
+
+                let Some(policy) = reconnect_policy.clone() else {
+                    // No reconnect policy: the error is terminal, so it's
+                    // the only signal consumers will ever get.
+                    broadcast_error(e).await;
+                    *state.write().await = ClientState::Error;
+                    break 'session;
+                };
+
+                // A policy is set, so the error is not necessarily terminal:
+                // suppress it from the stream for now and let a successful
+                // reconnect surface only via `subscribe_reconnect`'s boundary
+                // tick.
+                *state.write().await = ClientState::Reconnecting;
+                let mut backoff = policy.initial_backoff;
+                let mut reconnected = false;
+
+                for attempt in 1..=policy.max_attempts {
+                    info!(
+                        "Reconnect attempt {}/{} after transport error",
+                        attempt, policy.max_attempts
+                    );
+                    tokio::time::sleep(backoff).await;
+
+                    let outcome = {
+                        let mut transport = transport.lock().await;
+                        transport.reconnect().await
+                    };
+                    if outcome.is_ok() {
+                        reconnected = true;
                         break;
                     }
+                    backoff *= 2;
                 }
+
+                if !reconnected {
+                    error!(
+                        "Giving up after {} reconnect attempts",
+                        policy.max_attempts
+                    );
+                    // The reconnect boundary never fired, so this is the
+                    // only way consumers learn the connection is gone.
+                    broadcast_error(e).await;
+                    *state.write().await = ClientState::Error;
+                    break 'session;
+                }
+
+                // Re-announce active sessions so the CLI can resume their
+                // context, if the caller has confirmed the CLI honors it.
+                if policy.resume_sessions {
+                    let active_sessions: Vec<String> =
+                        sessions.read().await.keys().cloned().collect();
+                    let mut transport = transport.lock().await;
+                    for session_id in active_sessions {
+                        let _ = transport
+                            .send_message(InputMessage::session_resume(session_id))
+                            .await;
+                    }
+                }
+
+                *state.write().await = ClientState::Connected;
+                reconnect_tx.send_modify(|count| *count += 1);
+                info!("Reconnected to Claude CLI after transport error");
+
+                // Loop back around and re-subscribe to the recovered transport's event stream.
             }
 
             debug!("Message receiver task ended");
@@ -418,7 +651,7 @@ impl ClaudeSDKClient {
     }
 }
 
-impl Drop for ClaudeSDKClient {
+impl<T: Transport + 'static> Drop for ClaudeSDKClient<T> {
     fn drop(&mut self) {
         // Try to disconnect gracefully
         let transport = self.transport.clone();
@@ -457,4 +690,117 @@ mod tests {
         let state = client.state.read().await;
         assert_eq!(*state, ClientState::Disconnected);
     }
+
+    #[tokio::test]
+    async fn test_mock_connect_and_disconnect() {
+        let options = ClaudeCodeOptions::default();
+        let transport = MockTransport::from_messages(Vec::new());
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        assert!(!client.is_connected().await);
+        client.connect(None).await.unwrap();
+        assert!(client.is_connected().await);
+
+        client.disconnect().await.unwrap();
+        assert!(!client.is_connected().await);
+    }
+
+    #[tokio::test]
+    async fn test_mock_receive_messages_ends_when_exhausted() {
+        let options = ClaudeCodeOptions::default();
+        let transport = MockTransport::from_messages(Vec::new());
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        client.connect(None).await.unwrap();
+
+        let mut messages = client.receive_messages().await;
+        assert!(messages.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_mock_interrupt_acknowledged() {
+        let options = ClaudeCodeOptions::default();
+        // Have the mock ack whatever interrupt request it actually receives,
+        // rather than pre-scripting a response keyed to "interrupt_1" and
+        // relying on the receiver task not draining it before `interrupt()`
+        // registers its oneshot.
+        let transport = MockTransport::new(Vec::new()).with_auto_interrupt_ack();
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        client.connect(None).await.unwrap();
+
+        client.interrupt().await.unwrap();
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_request_tracks_per_session_data() {
+        let options = ClaudeCodeOptions::default();
+        let transport = MockTransport::from_messages(Vec::new());
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        client.connect(None).await.unwrap();
+        client
+            .send_request("hi".to_string(), Some("session-a".to_string()))
+            .await
+            .unwrap();
+        client
+            .send_request("hi".to_string(), Some("session-b".to_string()))
+            .await
+            .unwrap();
+
+        let mut sessions = client.get_sessions().await;
+        sessions.sort();
+        assert_eq!(sessions, vec!["session-a".to_string(), "session-b".to_string()]);
+
+        client.close_session("session-a").await;
+        assert_eq!(client.get_sessions().await, vec!["session-b".to_string()]);
+
+        client.disconnect().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_transport_error() {
+        let mut options = ClaudeCodeOptions::default();
+        options.reconnect = Some(ReconnectPolicy {
+            max_attempts: 1,
+            initial_backoff: std::time::Duration::from_millis(1),
+            ..Default::default()
+        });
+
+        let transport = MockTransport::new(vec![TransportEvent::Message(Err(
+            SdkError::ControlRequestError("transport dropped".to_string()),
+        ))]);
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        let mut reconnects = client.subscribe_reconnect();
+        client.connect(None).await.unwrap();
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), reconnects.changed())
+            .await
+            .expect("expected a reconnect notification")
+            .unwrap();
+        assert_eq!(*reconnects.borrow(), 1);
+        assert!(client.is_connected().await);
+    }
+
+    #[tokio::test]
+    async fn test_receive_messages_for_supports_multiple_subscribers() {
+        let options = ClaudeCodeOptions::default();
+        let transport = MockTransport::new(vec![TransportEvent::Message(Err(
+            SdkError::ControlRequestError("transport dropped".to_string()),
+        ))]);
+        let mut client = ClaudeSDKClient::from_transport(options, transport);
+
+        // Subscribe twice before connecting, so both are attached to the
+        // broadcast before the receiver task fans the error out.
+        let mut first = client.receive_messages_for(DEFAULT_SESSION_ID).await;
+        let mut second = client.receive_messages_for(DEFAULT_SESSION_ID).await;
+
+        client.connect(None).await.unwrap();
+
+        assert!(first.next().await.expect("first subscriber missed the event").is_err());
+        assert!(second.next().await.expect("second subscriber missed the event").is_err());
+    }
 }
@@ -0,0 +1,172 @@
+//! In-memory transport for exercising client logic without a real CLI subprocess
+//!
+//! [`MockTransport`] replays a scripted list of [`TransportEvent`]s, so tests
+//! can drive [`crate::ClaudeSDKClient`] end-to-end without spawning `claude`.
+
+use super::{InputMessage, Transport, TransportEvent};
+use crate::{
+    errors::Result,
+    types::{ControlRequest, ControlResponse, Message},
+};
+use async_trait::async_trait;
+use futures::stream::Stream;
+use std::pin::Pin;
+use std::sync::Mutex;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// A [`Transport`] driven entirely by a scripted list of events
+///
+/// Construct with [`MockTransport::new`] (or [`MockTransport::from_messages`]
+/// for the common case of plain messages), then build a client around it with
+/// `ClaudeSDKClient::from_transport`.
+pub struct MockTransport {
+    connected: bool,
+    /// Scripted events, handed off (once) to the channel behind the next
+    /// `receive_events` call rather than returned as a stream over `self`.
+    scripted_events: Vec<TransportEvent>,
+    /// Sender side of whichever channel is currently backing `receive_events`,
+    /// so `send_control_request` can push an ack onto the live event stream.
+    /// Kept only when auto-acking interrupts; otherwise the stream ends once
+    /// the scripted events have been delivered.
+    events_tx: Option<mpsc::UnboundedSender<TransportEvent>>,
+    sent_messages: Mutex<Vec<InputMessage>>,
+    sent_control_request_ids: Mutex<Vec<String>>,
+    reconnect_outcomes: Mutex<Vec<Result<()>>>,
+    auto_ack_interrupts: bool,
+}
+
+impl MockTransport {
+    /// Create a mock transport that yields `events`, in order, from `receive_events`
+    pub fn new(events: Vec<TransportEvent>) -> Self {
+        Self {
+            connected: false,
+            scripted_events: events,
+            events_tx: None,
+            sent_messages: Mutex::new(Vec::new()),
+            sent_control_request_ids: Mutex::new(Vec::new()),
+            reconnect_outcomes: Mutex::new(Vec::new()),
+            auto_ack_interrupts: false,
+        }
+    }
+
+    /// Create a mock transport that yields only plain messages, no control responses
+    pub fn from_messages(messages: Vec<Result<Message>>) -> Self {
+        Self::new(messages.into_iter().map(TransportEvent::Message).collect())
+    }
+
+    /// Script the outcomes `reconnect` hands back, one per call, in order
+    ///
+    /// Once exhausted, `reconnect` falls back to always succeeding.
+    pub fn with_reconnect_outcomes(self, outcomes: Vec<Result<()>>) -> Self {
+        Self {
+            reconnect_outcomes: Mutex::new(outcomes),
+            ..self
+        }
+    }
+
+    /// Make `send_control_request` answer every `Interrupt` with a successful
+    /// `InterruptAck`, pushed onto the event stream as soon as the request
+    /// arrives (or queued for the next `receive_events` call if no event
+    /// stream has been handed out yet). With this set, the event stream stays
+    /// open after the scripted events run out.
+    ///
+    /// Without this, a test has to pre-script the ack as a plain event and
+    /// rely on the client registering its oneshot before the receiver task
+    /// drains that event - ordering that happens to hold today but isn't
+    /// guaranteed by anything. Acking from `send_control_request` ties the
+    /// response to the request that actually caused it.
+    pub fn with_auto_interrupt_ack(self) -> Self {
+        Self {
+            auto_ack_interrupts: true,
+            ..self
+        }
+    }
+
+    /// Messages handed to `send_message`, in order, for assertions
+    pub fn sent_messages(&self) -> Vec<InputMessage> {
+        self.sent_messages.lock().unwrap().clone()
+    }
+
+    /// Request IDs of control requests handed to `send_control_request`, in order
+    pub fn sent_control_request_ids(&self) -> Vec<String> {
+        self.sent_control_request_ids.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn connect(&mut self) -> Result<()> {
+        self.connected = true;
+        Ok(())
+    }
+
+    async fn send_message(&mut self, message: InputMessage) -> Result<()> {
+        self.sent_messages.lock().unwrap().push(message);
+        Ok(())
+    }
+
+    fn receive_events(&mut self) -> Pin<Box<dyn Stream<Item = TransportEvent> + Send>> {
+        // A fresh channel each call, seeded with whatever scripted events
+        // haven't been delivered yet, so a reconnect gets its own event
+        // source instead of reusing one that was already drained.
+        let (tx, rx) = mpsc::unbounded_channel();
+        for event in std::mem::take(&mut self.scripted_events) {
+            let _ = tx.send(event);
+        }
+        self.events_tx = if self.auto_ack_interrupts { Some(tx) } else { None };
+        Box::pin(UnboundedReceiverStream::new(rx))
+    }
+
+    async fn send_control_request(&mut self, request: ControlRequest) -> Result<()> {
+        match &request {
+            ControlRequest::Interrupt { request_id } => {
+                self.sent_control_request_ids
+                    .lock()
+                    .unwrap()
+                    .push(request_id.clone());
+                if self.auto_ack_interrupts {
+                    let ack = TransportEvent::Control(ControlResponse::InterruptAck {
+                        request_id: request_id.clone(),
+                        success: true,
+                    });
+                    match &self.events_tx {
+                        Some(tx) => {
+                            let _ = tx.send(ack);
+                        }
+                        None => self.scripted_events.push(ack),
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    async fn disconnect(&mut self) -> Result<()> {
+        self.connected = false;
+        Ok(())
+    }
+
+    async fn reconnect(&mut self) -> Result<()> {
+        let outcome = {
+            let mut outcomes = self.reconnect_outcomes.lock().unwrap();
+            if outcomes.is_empty() {
+                None
+            } else {
+                Some(outcomes.remove(0))
+            }
+        };
+
+        match outcome {
+            Some(Ok(())) | None => {
+                self.connected = true;
+                Ok(())
+            }
+            Some(Err(e)) => Err(e),
+        }
+    }
+}
@@ -11,8 +11,12 @@ use async_trait::async_trait;
 use futures::stream::Stream;
 use std::pin::Pin;
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
 pub mod subprocess;
 
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::MockTransport;
 pub use subprocess::SubprocessTransport;
 
 /// Input message structure for sending to Claude
@@ -43,6 +47,27 @@ impl InputMessage {
         }
     }
 
+    /// Re-announce an existing session to the CLI, e.g. after a reconnect
+    ///
+    /// Carries no new user content; it's meant to let the CLI know this
+    /// session id is still active so it can resume the conversation's
+    /// context. The `{"role":"system","content":"resume"}` body is a
+    /// best-effort guess at the CLI's resume protocol, not one confirmed
+    /// against it, so sending it may be a silent no-op - callers only see
+    /// it sent when `ReconnectPolicy::resume_sessions` is explicitly
+    /// enabled.
+    pub fn session_resume(session_id: String) -> Self {
+        Self {
+            r#type: "user".to_string(),
+            message: serde_json::json!({
+                "role": "system",
+                "content": "resume"
+            }),
+            parent_tool_use_id: None,
+            session_id,
+        }
+    }
+
     /// Create a tool result message
     pub fn tool_result(
         tool_use_id: String,
@@ -67,6 +92,21 @@ impl InputMessage {
     }
 }
 
+/// An item produced by a transport's event stream
+///
+/// Control responses are multiplexed onto the same stream as regular
+/// messages so that exactly one task ever reads the underlying transport.
+/// Without this, a reader pulling control responses and a reader pulling
+/// messages would race for the same underlying stream. See
+/// `ClaudeSDKClient::start_message_receiver` for how the two are demuxed.
+#[derive(Debug)]
+pub enum TransportEvent {
+    /// A regular protocol message (or an error encountered reading one)
+    Message(Result<Message>),
+    /// A control response correlated to a previously sent `ControlRequest`
+    Control(ControlResponse),
+}
+
 /// Transport trait for communicating with Claude CLI
 #[async_trait]
 pub trait Transport: Send + Sync {
@@ -76,21 +116,37 @@ pub trait Transport: Send + Sync {
     /// Send a message to Claude
     async fn send_message(&mut self, message: InputMessage) -> Result<()>;
 
-    /// Receive messages from Claude as a stream
-    fn receive_messages(&mut self) -> Pin<Box<dyn Stream<Item = Result<Message>> + Send + '_>>;
+    /// Receive messages and control responses from Claude as a single event stream
+    ///
+    /// Implementations must multiplex both kinds of protocol traffic onto
+    /// this one stream; it is the only way a caller reads from the transport.
+    ///
+    /// The returned stream must not borrow `self`: callers lock the transport
+    /// only long enough to obtain it, then read from it without holding that
+    /// lock, so a concurrent `send_message`/`send_control_request` is never
+    /// blocked behind an in-progress read. Implementations should back this
+    /// with an owned channel fed by their own internal read loop rather than
+    /// a stream over `&mut self`.
+    fn receive_events(&mut self) -> Pin<Box<dyn Stream<Item = TransportEvent> + Send>>;
 
     /// Send a control request (e.g., interrupt)
     async fn send_control_request(&mut self, request: ControlRequest) -> Result<()>;
 
-    /// Receive control responses
-    async fn receive_control_response(&mut self) -> Result<Option<ControlResponse>>;
-
     /// Check if the transport is connected
     #[allow(dead_code)]
     fn is_connected(&self) -> bool;
 
     /// Disconnect from the Claude CLI
     async fn disconnect(&mut self) -> Result<()>;
+
+    /// Re-establish a dropped connection, e.g. by respawning the CLI subprocess
+    ///
+    /// The default implementation just disconnects and connects again;
+    /// implementations with cheaper or more specific recovery can override it.
+    async fn reconnect(&mut self) -> Result<()> {
+        self.disconnect().await?;
+        self.connect().await
+    }
 }
 
 /// Transport state
@@ -140,4 +196,12 @@ mod tests {
         assert!(json.contains(r#""tool_use_id":"tool-123""#));
         assert!(json.contains(r#""is_error":false"#));
     }
+
+    #[test]
+    fn test_input_message_session_resume() {
+        let msg = InputMessage::session_resume("session-789".to_string());
+        assert_eq!(msg.r#type, "user");
+        assert_eq!(msg.session_id, "session-789");
+        assert!(msg.parent_tool_use_id.is_none());
+    }
 }
\ No newline at end of file